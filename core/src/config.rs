@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+pub type TimeType = u8;
+
+/// Tunable parameters for a simulation run, loaded from a JSON5 file at
+/// startup. Any field omitted from the file falls back to its default.
+/// Also doubles as the CVar save format: front ends may mutate a live copy
+/// at runtime and write it back out with [`save`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimConfig {
+  pub width: usize,
+  pub height: usize,
+  pub scale: usize,
+  pub n_fish: usize,
+  pub n_sharks: usize,
+  pub fish_repro_time: TimeType,
+  pub shark_repro_time: TimeType,
+  pub shark_starves: TimeType,
+
+  /// Enables scent-gradient hunting. When `false`, a shark with no fish to eat
+  /// or path toward moves to a random empty neighbor. A* hunting within
+  /// `shark_vision_radius` runs either way; set that radius to 0 as well for
+  /// classic random-walk Wa-Tor behavior.
+  pub use_scent: bool,
+  pub scent_deposit: f32,
+  pub scent_diffusion: f32,
+  pub scent_decay: f32,
+
+  /// How far a shark can see fish to path toward, in wrapped Manhattan cells.
+  pub shark_vision_radius: usize,
+  /// A* node expansion cap per shark per tick before giving up on a path.
+  pub shark_astar_node_budget: usize,
+
+  /// Simulation ticks to run per redraw; raise to fast-forward, 0 to freeze.
+  pub ticks_per_frame: u32,
+}
+
+impl Default for SimConfig {
+  fn default() -> Self {
+    let scale = 2; // To scale size and starting number proportionally.
+    Self {
+      width: 320 * scale,
+      height: 240 * scale,
+      scale,
+      n_fish: 3000 * scale * scale,
+      n_sharks: 1000 * scale * scale,
+      fish_repro_time: 60,
+      shark_repro_time: 35,
+      shark_starves: 30,
+      use_scent: false,
+      scent_deposit: 1.0,
+      scent_diffusion: 0.2,
+      scent_decay: 0.95,
+      shark_vision_radius: 8,
+      shark_astar_node_budget: 256,
+      ticks_per_frame: 1,
+    }
+  }
+}
+
+const CONFIG_PATH: &str = "wator.json5";
+
+/// Loads `SimConfig` from [`CONFIG_PATH`], falling back to [`SimConfig::default`]
+/// when the file is missing or fails to parse.
+pub fn load() -> SimConfig {
+  match std::fs::read_to_string(CONFIG_PATH) {
+    Ok(contents) => json5::from_str(&contents).unwrap_or_else(|err| {
+      eprintln!("failed to parse {CONFIG_PATH}: {err}, using defaults");
+      SimConfig::default()
+    }),
+    Err(_) => SimConfig::default(),
+  }
+}
+
+/// Serializes `config` as JSON5 and writes it to [`CONFIG_PATH`], overwriting
+/// whatever is there. Used to persist CVar tuning done at runtime.
+pub fn save(config: &SimConfig) -> std::io::Result<()> {
+  let contents = json5::to_string(config).map_err(std::io::Error::other)?;
+  std::fs::write(CONFIG_PATH, contents)
+}
@@ -0,0 +1,499 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+
+pub mod config;
+
+pub use config::{SimConfig, TimeType};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Point {
+  x: isize,
+  y: isize,
+}
+
+fn nudge_into_range(x: isize, m: isize) -> isize {
+  if x < 0 {
+    x + m
+  } else if x >= m {
+    x - m
+  } else {
+    x
+  }
+}
+
+impl Point {
+  fn from_ix(ix: usize, width: usize) -> Self {
+    Self { x: (ix % width) as isize, y: (ix / width) as isize }
+  }
+
+  fn offset(&self, dx: isize, dy: isize, width: usize, height: usize) -> Point {
+    let x = nudge_into_range(self.x + dx, width as isize);
+    let y = nudge_into_range(self.y + dy, height as isize);
+    Point { x, y }
+  }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct Shark {
+  pos: Point,
+  repro_time: TimeType,
+  starve: TimeType,
+}
+
+impl Shark {
+  fn new(pos: Point) -> Self { Self { pos, repro_time: 0, starve: 0 } }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct Fish {
+  pos: Point,
+  repro_time: TimeType,
+}
+
+impl Fish {
+  fn new(pos: Point) -> Self { Self { pos, repro_time: 0 } }
+}
+
+#[repr(u8)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Content {
+  Empty = 0,
+
+  Fish = 1,
+  NewFish = 1 | 4,
+
+  Shark = 2,
+  NewShark = 2 | 4,
+  FedShark = 2 | 8,
+}
+
+impl Content {
+  fn is_empty(&self) -> bool {
+    *self as u8 == 0
+  }
+  fn is_fish(&self) -> bool {
+    *self as u8 & 1 != 0
+  }
+  #[allow(unused)]
+  fn is_shark(&self) -> bool {
+    *self as u8 & 2 != 0
+  }
+}
+
+struct Board {
+  data: Vec<Content>,
+  width: usize,
+  height: usize,
+}
+
+impl Board {
+  fn new(width: usize, height: usize) -> Self {
+    Self { data: vec![Content::Empty; width * height], width, height }
+  }
+
+  fn get(&self, p: Point) -> Content {
+    let ix = p.y as usize * self.width + p.x as usize;
+    self.data[ix]
+  }
+
+  fn get_mut(&mut self, p: Point) -> &mut Content {
+    let ix = p.y as usize * self.width + p.x as usize;
+    &mut self.data[ix]
+  }
+}
+
+fn wrapped_manhattan(a: Point, b: Point, width: usize, height: usize) -> u32 {
+  let dx = a.x.abs_diff(b.x);
+  let dy = a.y.abs_diff(b.y);
+  (dx.min(width - dx) + dy.min(height - dy)) as u32
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct AstarNode {
+  f: u32,
+  point: Point,
+}
+
+// Ordered by ascending `f` so `BinaryHeap` (a max-heap) pops the lowest cost first.
+impl Ord for AstarNode {
+  fn cmp(&self, other: &Self) -> Ordering { other.f.cmp(&self.f) }
+}
+impl PartialOrd for AstarNode {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+/// A* over the toroidal board: neighbors are the four adjacent cells that are
+/// either empty or `goal` itself (fish are only walkable as the final step),
+/// with uniform step cost and the wrapped Manhattan distance as heuristic.
+/// Expansion is capped at `node_budget`; returns `None` if the budget runs
+/// out before reaching `goal`.
+fn astar(occupied: &Board, start: Point, goal: Point, node_budget: usize) -> Option<Vec<Point>> {
+  let (width, height) = (occupied.width, occupied.height);
+  let h = |p: Point| wrapped_manhattan(p, goal, width, height);
+
+  let mut open = BinaryHeap::new();
+  open.push(AstarNode { f: h(start), point: start });
+
+  let mut came_from: HashMap<Point, Point> = HashMap::new();
+  let mut g_score: HashMap<Point, u32> = HashMap::new();
+  g_score.insert(start, 0);
+
+  let mut expanded = 0;
+  while let Some(AstarNode { point: current, .. }) = open.pop() {
+    if current == goal {
+      let mut path = vec![current];
+      while let Some(&prev) = came_from.get(path.last().unwrap()) {
+        path.push(prev);
+      }
+      path.pop(); // Drop `start`.
+      path.reverse();
+      return Some(path);
+    }
+
+    expanded += 1;
+    if expanded > node_budget {
+      return None;
+    }
+
+    for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+      let neighbor = current.offset(dx, dy, width, height);
+      let walkable = neighbor == goal || occupied.get(neighbor).is_empty();
+      if !walkable {
+        continue;
+      }
+
+      let tentative_g = g_score[&current] + 1;
+      if tentative_g < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
+        came_from.insert(neighbor, current);
+        g_score.insert(neighbor, tentative_g);
+        open.push(AstarNode { f: tentative_g + h(neighbor), point: neighbor });
+      }
+    }
+  }
+
+  None
+}
+
+/// Finds the closest fish to `start` within `radius` wrapped-Manhattan cells,
+/// scanning outward ring by ring so the first hit is nearest.
+fn find_nearest_fish(occupied: &Board, start: Point, radius: usize) -> Option<Point> {
+  let (width, height) = (occupied.width, occupied.height);
+  for dist in 1..=radius as isize {
+    for dx in -dist..=dist {
+      let dy = dist - dx.abs();
+      for sy in [dy, -dy] {
+        let p = start.offset(dx, sy, width, height);
+        if occupied.get(p).is_fish() {
+          return Some(p);
+        }
+        if dy == 0 {
+          break;
+        }
+      }
+    }
+  }
+  None
+}
+
+/// A diffusing scent field fish emit and sharks can follow uphill when
+/// hunting blind, parallel to `Board` but over `f32` intensities.
+struct Scent {
+  data: Vec<f32>,
+  width: usize,
+  height: usize,
+}
+
+impl Scent {
+  fn new(width: usize, height: usize) -> Self {
+    Self { data: vec![0.0; width * height], width, height }
+  }
+
+  fn ix(&self, p: Point) -> usize { p.y as usize * self.width + p.x as usize }
+
+  fn get(&self, p: Point) -> f32 { self.data[self.ix(p)] }
+
+  fn deposit(&mut self, p: Point, amount: f32) {
+    let ix = self.ix(p);
+    self.data[ix] += amount;
+  }
+
+  /// Blends every cell with the mean of its four toroidal neighbors, then
+  /// applies exponential decay: `next = decay * ((1 - d) * cur + d * mean)`.
+  fn step(&mut self, decay: f32, d: f32) {
+    let (width, height) = (self.width, self.height);
+    let mut next = vec![0.0; self.data.len()];
+    for y in 0..height as isize {
+      for x in 0..width as isize {
+        let p = Point { x, y };
+        let mean: f32 = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+          .iter()
+          .map(|&(dx, dy)| self.get(p.offset(dx, dy, width, height)))
+          .sum::<f32>()
+          / 4.0;
+        next[self.ix(p)] = decay * ((1.0 - d) * self.get(p) + d * mean);
+      }
+    }
+    self.data = next;
+  }
+}
+
+fn clear_by_cond<T: Copy>(
+  vec: &mut Vec<T>,
+  should_remove: impl Fn(&T) -> bool,
+) -> Vec<T> {
+  let mut i = 0;
+  let mut removed = vec![];
+  while i < vec.len() {
+    if should_remove(&vec[i]) {
+      removed.push(vec.swap_remove(i));
+    } else {
+      i += 1;
+    }
+  }
+  removed
+}
+
+struct World {
+  occupied: Board,
+  sharks: Vec<Shark>,
+  fishes: Vec<Fish>,
+  fish_repro_time: TimeType,
+  shark_repro_time: TimeType,
+  shark_starves: TimeType,
+  scent: Option<Scent>,
+  scent_deposit: f32,
+  scent_diffusion: f32,
+  scent_decay: f32,
+  shark_vision_radius: usize,
+  shark_astar_node_budget: usize,
+}
+
+impl World {
+  fn new(config: &SimConfig) -> Self {
+    let mut occupied = Board::new(config.width, config.height);
+
+    let rng = &mut rand::thread_rng();
+    let mut indices = (0..config.width * config.height)
+      .choose_multiple(rng, config.n_fish + config.n_sharks)
+      .into_iter();
+
+    let mut fishes = vec![];
+    for ix in indices.by_ref().take(config.n_fish) {
+      let fish = Fish {
+        pos: Point::from_ix(ix, config.width),
+        repro_time: rng.gen_range(1..=config.fish_repro_time),
+      };
+      *occupied.get_mut(fish.pos) = Content::Fish;
+      fishes.push(fish);
+    }
+
+    let mut sharks = vec![];
+    for ix in indices {
+      let shark = Shark {
+        pos: Point::from_ix(ix, config.width),
+        repro_time: rng.gen_range(1..=config.shark_repro_time),
+        starve: 0,
+      };
+      *occupied.get_mut(shark.pos) = Content::Shark;
+      sharks.push(shark);
+    }
+
+    World {
+      occupied,
+      sharks,
+      fishes,
+      fish_repro_time: config.fish_repro_time,
+      shark_repro_time: config.shark_repro_time,
+      shark_starves: config.shark_starves,
+      scent: config.use_scent.then(|| Scent::new(config.width, config.height)),
+      scent_deposit: config.scent_deposit,
+      scent_diffusion: config.scent_diffusion,
+      scent_decay: config.scent_decay,
+      shark_vision_radius: config.shark_vision_radius,
+      shark_astar_node_budget: config.shark_astar_node_budget,
+    }
+  }
+
+  fn update(&mut self) {
+    let rng = &mut rand::thread_rng();
+    let mut directions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+    let width = self.occupied.width;
+    let height = self.occupied.height;
+
+    let mut new_fishes = vec![];
+    for fish in &mut self.fishes {
+      directions.shuffle(rng);
+      let start = fish.pos;
+
+      // Move.
+      for (dx, dy) in directions {
+        if self.occupied.get(fish.pos.offset(dx, dy, width, height)).is_empty() {
+          *self.occupied.get_mut(fish.pos) = Content::Empty;
+          fish.pos = fish.pos.offset(dx, dy, width, height);
+          *self.occupied.get_mut(fish.pos) = Content::Fish;
+          break;
+        }
+      }
+
+      // Breed if moved.
+      if start != fish.pos {
+        fish.repro_time += 1;
+        if fish.repro_time >= self.fish_repro_time {
+          fish.repro_time = 0;
+          new_fishes.push(Fish::new(start));
+          *self.occupied.get_mut(start) = Content::NewFish;
+        }
+      }
+    }
+    self.fishes.extend(new_fishes);
+
+    let mut fishes_to_remove = std::collections::HashSet::new();
+    let mut new_sharks = vec![];
+    for shark in &mut self.sharks {
+      directions.shuffle(rng);
+      let start = shark.pos;
+
+      // Eat.
+      for (dx, dy) in directions {
+        if self.occupied.get(shark.pos.offset(dx, dy, width, height)).is_fish() {
+          fishes_to_remove.insert(shark.pos.offset(dx, dy, width, height));
+          shark.starve = 0;
+          *self.occupied.get_mut(shark.pos) = Content::Empty;
+          shark.pos = shark.pos.offset(dx, dy, width, height);
+          *self.occupied.get_mut(shark.pos) = Content::FedShark;
+          break;
+        }
+      }
+
+      // Hunt: path toward the nearest fish within vision range. Falls through
+      // to the scent/random move below if none is visible or reachable.
+      if start == shark.pos {
+        if let Some(fish_pos) =
+          find_nearest_fish(&self.occupied, shark.pos, self.shark_vision_radius)
+        {
+          if let Some(path) =
+            astar(&self.occupied, shark.pos, fish_pos, self.shark_astar_node_budget)
+          {
+            if let Some(&next) = path.first() {
+              *self.occupied.get_mut(shark.pos) = Content::Empty;
+              shark.pos = next;
+              *self.occupied.get_mut(shark.pos) = Content::Shark;
+            }
+          }
+        }
+      }
+
+      // Move if not already moved: follow the scent gradient uphill when
+      // available, otherwise take the first empty direction.
+      if start == shark.pos {
+        if let Some(scent) = &self.scent {
+          let mut best: Option<(Point, f32)> = None;
+          for (dx, dy) in directions {
+            let np = shark.pos.offset(dx, dy, width, height);
+            if self.occupied.get(np).is_empty() {
+              let s = scent.get(np);
+              if best.is_none_or(|(_, best_s)| s > best_s) {
+                best = Some((np, s));
+              }
+            }
+          }
+          if let Some((np, _)) = best {
+            *self.occupied.get_mut(shark.pos) = Content::Empty;
+            shark.pos = np;
+            *self.occupied.get_mut(shark.pos) = Content::Shark;
+          }
+        } else {
+          for (dx, dy) in directions {
+            if self.occupied.get(shark.pos.offset(dx, dy, width, height)).is_empty() {
+              *self.occupied.get_mut(shark.pos) = Content::Empty;
+              shark.pos = shark.pos.offset(dx, dy, width, height);
+              *self.occupied.get_mut(shark.pos) = Content::Shark;
+              break;
+            }
+          }
+        }
+      }
+
+      // Breed if moved.
+      if start != shark.pos {
+        shark.repro_time += 1;
+        if shark.repro_time == self.shark_repro_time {
+          shark.repro_time = 0;
+          new_sharks.push(Shark::new(start));
+          *self.occupied.get_mut(start) = Content::NewShark;
+        }
+      }
+
+      shark.starve += 1;
+    }
+    self.sharks.extend(new_sharks);
+
+    // Clear eaten fish.
+    clear_by_cond(&mut self.fishes, |&fish| {
+      fishes_to_remove.contains(&fish.pos)
+    });
+
+    // Kill starved sharks.
+    for rem in clear_by_cond(&mut self.sharks, |&shark| {
+      shark.starve >= self.shark_starves
+    }) {
+      *self.occupied.get_mut(rem.pos) = Content::Empty;
+    }
+
+    // Fish deposit scent every tick, then let it diffuse and decay.
+    if let Some(scent) = &mut self.scent {
+      for fish in &self.fishes {
+        scent.deposit(fish.pos, self.scent_deposit);
+      }
+      scent.step(self.scent_decay, self.scent_diffusion);
+    }
+  }
+}
+
+/// Render-agnostic simulation handle shared by the desktop and web front ends.
+pub struct Sim {
+  world: World,
+}
+
+impl Sim {
+  pub fn new(config: &SimConfig) -> Self {
+    Self { world: World::new(config) }
+  }
+
+  pub fn width(&self) -> usize { self.world.occupied.width }
+  pub fn height(&self) -> usize { self.world.occupied.height }
+
+  pub fn update(&mut self) { self.world.update(); }
+
+  /// Reseeds the simulation from scratch using `config`, discarding the
+  /// current board.
+  pub fn reset(&mut self, config: &SimConfig) { self.world = World::new(config); }
+
+  pub fn set_fish_repro_time(&mut self, v: TimeType) { self.world.fish_repro_time = v; }
+  pub fn set_shark_repro_time(&mut self, v: TimeType) { self.world.shark_repro_time = v; }
+  pub fn set_shark_starves(&mut self, v: TimeType) { self.world.shark_starves = v; }
+
+  /// Fills `frame` with one RGBA pixel (4 bytes) per board cell, in row-major order.
+  pub fn draw(&self, frame: &mut [u8]) {
+    let width = self.world.occupied.width;
+    for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
+      let p = Point::from_ix(i, width);
+
+      let rgba = match self.world.occupied.get(p) {
+        Content::Empty    => [0x00, 0x00, 0x00, 0xff],
+
+        Content::Fish     => [0x00, 0x99, 0x00, 0xff],
+        Content::NewFish  => [0x00, 0xff, 0x00, 0xff],
+
+        Content::Shark    => [0xff, 0x00, 0x00, 0xff],
+        Content::NewShark => [0xff, 0xff, 0xff, 0xff],
+        Content::FedShark => [0xff, 0xff, 0x00, 0xff],
+      };
+
+      pixel.copy_from_slice(&rgba);
+    }
+  }
+}
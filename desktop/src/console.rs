@@ -0,0 +1,98 @@
+use wator_core::{config, Sim, SimConfig, TimeType};
+
+/// A single named, live-tunable knob backed by a `SimConfig` field.
+pub struct CVar {
+  pub name: &'static str,
+  pub get: fn(&SimConfig) -> f64,
+  pub set: fn(&mut SimConfig, f64),
+}
+
+pub const CVARS: &[CVar] = &[
+  CVar {
+    name: "fish_repro_time",
+    get: |c| c.fish_repro_time as f64,
+    set: |c, v| c.fish_repro_time = v.max(1.0) as TimeType,
+  },
+  CVar {
+    name: "shark_repro_time",
+    get: |c| c.shark_repro_time as f64,
+    set: |c, v| c.shark_repro_time = v.max(1.0) as TimeType,
+  },
+  CVar {
+    name: "shark_starves",
+    get: |c| c.shark_starves as f64,
+    set: |c, v| c.shark_starves = v.max(1.0) as TimeType,
+  },
+  CVar {
+    name: "ticks_per_frame",
+    get: |c| c.ticks_per_frame as f64,
+    set: |c, v| c.ticks_per_frame = v.max(0.0) as u32,
+  },
+];
+
+fn find(name: &str) -> Option<&'static CVar> {
+  CVARS.iter().find(|cvar| cvar.name == name)
+}
+
+/// Pushes the live-tunable fields of `config` into a running `Sim`. Fields
+/// that require a reseed (board size, creature counts, ...) are intentionally
+/// excluded; use [`Sim::reset`] for those.
+pub fn apply(sim: &mut Sim, config: &SimConfig) {
+  sim.set_fish_repro_time(config.fish_repro_time);
+  sim.set_shark_repro_time(config.shark_repro_time);
+  sim.set_shark_starves(config.shark_starves);
+}
+
+/// Text console: a `set`/`get`/`list`/`save` command line, its pause and
+/// single-step state, and the text buffer currently being typed. Neither the
+/// buffer nor command output is drawn in the window; results go to stderr.
+#[derive(Default)]
+pub struct Console {
+  pub active: bool,
+  pub paused: bool,
+  pub step_once: bool,
+  pub buffer: String,
+}
+
+impl Console {
+  pub fn new() -> Self { Self::default() }
+
+  pub fn toggle(&mut self) {
+    self.active = !self.active;
+    self.buffer.clear();
+  }
+
+  /// Parses and runs one command line against `config`, logging the result
+  /// to stderr. Does not itself push changes into a running `Sim` — call
+  /// [`apply`] afterward.
+  pub fn submit(&mut self, line: &str, config: &mut SimConfig) {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+      Some("set") => match (parts.next(), parts.next().and_then(|v| v.parse::<f64>().ok())) {
+        (Some(name), Some(value)) => match find(name) {
+          Some(cvar) => {
+            (cvar.set)(config, value);
+            eprintln!("{name} = {}", (cvar.get)(config));
+          }
+          None => eprintln!("unknown cvar: {name}"),
+        },
+        _ => eprintln!("usage: set <cvar> <value>"),
+      },
+      Some("get") => match parts.next().and_then(find) {
+        Some(cvar) => eprintln!("{} = {}", cvar.name, (cvar.get)(config)),
+        None => eprintln!("usage: get <cvar>"),
+      },
+      Some("list") => {
+        for cvar in CVARS {
+          eprintln!("{} = {}", cvar.name, (cvar.get)(config));
+        }
+      }
+      Some("save") => match config::save(config) {
+        Ok(()) => eprintln!("config saved"),
+        Err(err) => eprintln!("failed to save config: {err}"),
+      },
+      Some(other) => eprintln!("unknown command: {other}"),
+      None => {}
+    }
+  }
+}
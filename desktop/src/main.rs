@@ -0,0 +1,113 @@
+use pixels::{Error, Pixels, SurfaceTexture};
+use winit::dpi::LogicalSize;
+use winit::event::{Event, VirtualKeyCode, WindowEvent};
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::window::WindowBuilder;
+use winit_input_helper::WinitInputHelper;
+
+use wator_core::{config, Sim};
+
+mod console;
+
+use console::Console;
+
+fn main() -> Result<(), Error> {
+  let mut config = config::load();
+
+  let event_loop = EventLoop::new();
+  let mut input = WinitInputHelper::new();
+  let window = {
+    let size = LogicalSize::new(config.width as f64, config.height as f64);
+    WindowBuilder::new()
+      .with_title("Hello Pixels")
+      .with_inner_size(size)
+      .with_min_inner_size(size)
+      .build(&event_loop)
+      .unwrap()
+  };
+
+  let mut pixels = {
+    let window_size = window.inner_size();
+    let surface_texture =
+      SurfaceTexture::new(window_size.width, window_size.height, &window);
+    Pixels::new(config.width as u32, config.height as u32, surface_texture)?
+  };
+  let mut sim = Sim::new(&config);
+  let mut console = Console::new();
+
+  event_loop.run(move |event, _, control_flow| {
+    // Feed typed characters to the console while it's open.
+    if let Event::WindowEvent { event: WindowEvent::ReceivedCharacter(c), .. } = &event {
+      if console.active && !c.is_control() {
+        console.buffer.push(*c);
+      }
+    }
+
+    // Draw the current frame
+    if let Event::RedrawRequested(_) = event {
+      if let Err(err) = pixels.render() {
+        eprintln!("pixels.render() failed: {err}");
+        *control_flow = ControlFlow::Exit;
+        return;
+      }
+
+      if !console.paused {
+        for _ in 0..config.ticks_per_frame {
+          sim.update();
+        }
+      } else if console.step_once {
+        sim.update();
+        console.step_once = false;
+      }
+      sim.draw(pixels.frame_mut());
+      window.request_redraw();
+    }
+
+    // Handle input events
+    if input.update(&event) {
+      // Close events
+      if input.key_pressed(VirtualKeyCode::Escape) || input.close_requested() {
+        *control_flow = ControlFlow::Exit;
+        return;
+      }
+
+      // Resize the window
+      if let Some(size) = input.window_resized() {
+        if let Err(err) = pixels.resize_surface(size.width, size.height) {
+          eprintln!("pixels.resize_surface() failed: {err}");
+          *control_flow = ControlFlow::Exit;
+          return;
+        }
+      }
+
+      // Grave toggles the console; while open, keys are typed text instead
+      // of game hotkeys.
+      if input.key_pressed(VirtualKeyCode::Grave) {
+        console.toggle();
+      }
+
+      if console.active {
+        if input.key_pressed(VirtualKeyCode::Return) {
+          let line = std::mem::take(&mut console.buffer);
+          console.submit(&line, &mut config);
+          console::apply(&mut sim, &config);
+        }
+        if input.key_pressed(VirtualKeyCode::Back) {
+          console.buffer.pop();
+        }
+      } else {
+        if input.key_pressed(VirtualKeyCode::P) {
+          console.paused = !console.paused;
+        }
+        if input.key_pressed(VirtualKeyCode::N) {
+          console.step_once = true;
+        }
+        if input.key_pressed(VirtualKeyCode::R) {
+          sim.reset(&config);
+        }
+      }
+
+      window.request_redraw();
+    }
+  });
+}
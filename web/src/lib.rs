@@ -0,0 +1,64 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{Clamped, JsCast};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, ImageData};
+
+use wator_core::{SimConfig, Sim};
+
+/// Starts the simulation, driving `canvas_id` once per animation frame.
+/// Resizes the canvas to the board dimensions and runs until the page unloads.
+#[wasm_bindgen]
+pub fn start(canvas_id: &str) -> Result<(), JsValue> {
+  console_error_panic_hook::set_once();
+
+  let config = SimConfig::default();
+  let sim = Rc::new(RefCell::new(Sim::new(&config)));
+
+  let window = web_sys::window().ok_or("no global `window`")?;
+  let document = window.document().ok_or("no document on `window`")?;
+  let canvas = document
+    .get_element_by_id(canvas_id)
+    .ok_or("canvas element not found")?
+    .dyn_into::<HtmlCanvasElement>()?;
+  canvas.set_width(config.width as u32);
+  canvas.set_height(config.height as u32);
+
+  let ctx = canvas
+    .get_context("2d")?
+    .ok_or("failed to get 2d context")?
+    .dyn_into::<CanvasRenderingContext2d>()?;
+
+  let mut frame = vec![0u8; config.width * config.height * 4];
+
+  let f = Rc::new(RefCell::new(None));
+  let g = f.clone();
+  *g.borrow_mut() = Some(Closure::new(move || {
+    {
+      let mut sim = sim.borrow_mut();
+      sim.update();
+      sim.draw(&mut frame);
+    }
+
+    if let Ok(image_data) = ImageData::new_with_u8_clamped_array_and_sh(
+      Clamped(&frame),
+      config.width as u32,
+      config.height as u32,
+    ) {
+      let _ = ctx.put_image_data(&image_data, 0.0, 0.0);
+    }
+
+    request_animation_frame(f.borrow().as_ref().unwrap());
+  }));
+  request_animation_frame(g.borrow().as_ref().unwrap());
+
+  Ok(())
+}
+
+fn request_animation_frame(f: &Closure<dyn FnMut()>) {
+  web_sys::window()
+    .expect("no global `window`")
+    .request_animation_frame(f.as_ref().unchecked_ref())
+    .expect("requestAnimationFrame failed");
+}